@@ -1,11 +1,12 @@
 // standard lib imports
-// input/output and time for refreshing 
+// input/output and time for refreshing
 use std::{
+    collections::{HashMap, VecDeque},
     io,
     time::{Duration, Instant},
 };
 
-// for terminal contorl 
+// for terminal contorl
 use crossterm::{
     event::{self, Event, KeyCode},
     execute,
@@ -17,97 +18,598 @@ use crossterm::{
 // TUI framework
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout},
-    style::{Color, Style},
-    widgets::{Block, Borders, Gauge, List, ListItem},
-    Terminal,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    symbols,
+    text::Span,
+    widgets::{
+        Axis, Block, Borders, Cell, Chart, Clear, Dataset, Gauge, GraphType, Paragraph, Row,
+        Table, TableState,
+    },
+    Frame, Terminal,
 };
 
 // sys info and proc
-use sysinfo::System;
+use sysinfo::{Components, Disks, Networks, Pid, System};
+
+// CLI parsing and config file deserialization
+use clap::{Parser, ValueEnum};
+use serde::Deserialize;
+
+// command-line flags. every override is optional so an unset flag falls through
+// to the config file (and then to the built-in defaults).
+#[derive(Parser)]
+#[command(name = "rustop", about = "a terminal system monitor")]
+struct Cli {
+    // refresh interval in milliseconds
+    #[arg(short, long)]
+    tick_rate: Option<u64>,
+
+    // number of processes to show (0 = all, scrollable)
+    #[arg(short = 'n', long)]
+    processes: Option<usize>,
+
+    // column the process table starts sorted on
+    #[arg(short, long)]
+    sort: Option<SortKey>,
+
+    // color theme
+    #[arg(long)]
+    theme: Option<Theme>,
+}
+
+// config file shape. mirrors `Cli` with every field optional so a partial file
+// only overrides the keys it sets.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct FileConfig {
+    tick_rate: Option<u64>,
+    processes: Option<usize>,
+    sort: Option<SortKey>,
+    theme: Option<Theme>,
+}
+
+// fully resolved configuration after merging CLI > file > defaults
+struct Config {
+    tick_rate: Duration,
+    processes: usize,
+    sort: SortKey,
+    theme: Theme,
+}
+
+impl Config {
+    // resolve the effective config: CLI flags win, then the config file, then
+    // the built-in defaults
+    fn resolve(cli: Cli, file: FileConfig) -> Self {
+        let tick_rate = cli.tick_rate.or(file.tick_rate).unwrap_or(800);
+        Config {
+            tick_rate: Duration::from_millis(tick_rate),
+            processes: cli.processes.or(file.processes).unwrap_or(0),
+            sort: cli.sort.or(file.sort).unwrap_or(SortKey::Cpu),
+            theme: cli.theme.or(file.theme).unwrap_or_default(),
+        }
+    }
+}
+
+// load the config file from the user config dir, if present. a missing file is
+// not an error; a malformed one falls back to defaults so the app still starts.
+fn load_file_config() -> FileConfig {
+    let Some(dir) = dirs::config_dir() else {
+        return FileConfig::default();
+    };
+    let path = dir.join("rustop").join("config.toml");
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => toml::from_str(&contents).unwrap_or_default(),
+        Err(_) => FileConfig::default(),
+    }
+}
+
+// selectable color themes for the CPU and memory trend charts
+#[derive(Clone, Copy, Default, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+enum Theme {
+    #[default]
+    Default,
+    Mono,
+}
+
+impl Theme {
+    fn cpu_color(&self) -> Color {
+        match self {
+            Theme::Default => Color::Green,
+            Theme::Mono => Color::White,
+        }
+    }
+
+    fn mem_color(&self) -> Color {
+        match self {
+            Theme::Default => Color::Cyan,
+            Theme::Mono => Color::Gray,
+        }
+    }
+}
+
+// bounded, time-stamped history of a single metric for the trend charts.
+// samples are kept sparse: we only push when the value changes, and drop
+// anything that has scrolled off the left edge of the window.
+struct TimedStats {
+    samples: VecDeque<(Instant, f64)>,
+    window: Duration,
+}
+
+impl TimedStats {
+    fn new(window: Duration) -> Self {
+        TimedStats {
+            samples: VecDeque::new(),
+            window,
+        }
+    }
+
+    // record a sample, collapsing runs of equal values and trimming the window
+    fn push(&mut self, now: Instant, value: f64) {
+        if self.samples.back().map(|&(_, v)| v) != Some(value) {
+            self.samples.push_back((now, value));
+        }
+        let cutoff = now - self.window;
+        // drop points fully outside the window, but keep one leading point so the
+        // left edge of the chart stays anchored at the start of the window
+        while self.samples.len() > 1 && self.samples[1].0 < cutoff {
+            self.samples.pop_front();
+        }
+    }
+
+    // points as (-seconds-ago, value) with x = 0 being `now`, so the line
+    // scrolls right-to-left as time passes
+    fn points(&self, now: Instant) -> Vec<(f64, f64)> {
+        self.samples
+            .iter()
+            .map(|&(t, v)| (-(now.duration_since(t).as_secs_f64()), v))
+            .collect()
+    }
+
+    // min/max over the window, used to autoscale the Y axis
+    fn bounds(&self) -> (f64, f64) {
+        let mut min = f64::MAX;
+        let mut max = f64::MIN;
+        for &(_, v) in &self.samples {
+            min = min.min(v);
+            max = max.max(v);
+        }
+        if self.samples.is_empty() {
+            (0.0, 1.0)
+        } else {
+            (min, max)
+        }
+    }
+
+    fn window_secs(&self) -> f64 {
+        self.window.as_secs_f64()
+    }
+}
+
+// which column the process table is sorted on
+#[derive(Clone, Copy, PartialEq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+enum SortKey {
+    Cpu,
+    Memory,
+    Pid,
+    Name,
+}
+
+impl SortKey {
+    // short label for the table title so the user can see the active sort
+    fn label(&self) -> &'static str {
+        match self {
+            SortKey::Cpu => "CPU",
+            SortKey::Memory => "MEM",
+            SortKey::Pid => "PID",
+            SortKey::Name => "NAME",
+        }
+    }
+}
+
+// which signal the kill popup will send to the selected process
+#[derive(Clone, Copy, PartialEq)]
+enum KillSignal {
+    Term,
+    Kill,
+}
+
+impl KillSignal {
+    fn label(&self) -> &'static str {
+        match self {
+            KillSignal::Term => "SIGTERM",
+            KillSignal::Kill => "SIGKILL",
+        }
+    }
+}
+
+// what the UI is currently showing: the table, or the kill confirmation popup
+enum Mode {
+    Normal,
+    // the pid/name being confirmed, plus the currently picked signal
+    ConfirmKill { pid: Pid, name: String, signal: KillSignal },
+}
+
+// shared application state. owns every sysinfo collector plus the previous-tick
+// samples (network byte totals) needed to turn cumulative counters into rates,
+// so individual panels stay stateless render functions.
+struct App {
+    sys: System,
+    networks: Networks,
+    disks: Disks,
+    components: Components,
+
+    cpu_hist: TimedStats,
+    mem_hist: TimedStats,
+
+    table_state: TableState,
+    sort_key: SortKey,
+    sort_desc: bool,
+    page_len: usize,
+    cpu_per_core: bool,
+
+    mode: Mode,
+    status: Option<(String, Instant)>,
+    status_ttl: Duration,
+
+    // row order of the current process table, so input can map selection -> pid
+    visible: Vec<(Pid, String)>,
+
+    // per-interface (total_rx, total_tx) from the last tick and when it was taken
+    net_prev: HashMap<String, (u64, u64)>,
+    net_prev_at: Instant,
+
+    // independently toggleable optional panels
+    show_network: bool,
+    show_disks: bool,
+    show_temps: bool,
+
+    // resolved presentation config: colors and the process-count cap (0 = all)
+    theme: Theme,
+    process_count: usize,
+}
+
+impl App {
+    fn new(config: &Config) -> Self {
+        let history_window = Duration::from_secs(10 * 60);
+        let mut table_state = TableState::default();
+        table_state.select(Some(0));
+        App {
+            sys: System::new_all(),
+            networks: Networks::new_with_refreshed_list(),
+            disks: Disks::new_with_refreshed_list(),
+            components: Components::new_with_refreshed_list(),
+            cpu_hist: TimedStats::new(history_window),
+            mem_hist: TimedStats::new(history_window),
+            table_state,
+            sort_key: config.sort,
+            sort_desc: true,
+            page_len: 1,
+            cpu_per_core: false,
+            mode: Mode::Normal,
+            status: None,
+            status_ttl: Duration::from_secs(4),
+            visible: Vec::new(),
+            net_prev: HashMap::new(),
+            net_prev_at: Instant::now(),
+            show_network: true,
+            show_disks: true,
+            show_temps: true,
+            theme: config.theme,
+            process_count: config.processes,
+        }
+    }
+
+    // compute per-interface rx/tx rates in bytes/sec from the delta since the
+    // previous tick, then remember the new totals for next time
+    fn network_rates(&mut self, now: Instant) -> Vec<(String, f64, f64)> {
+        let elapsed = now.duration_since(self.net_prev_at).as_secs_f64().max(1e-3);
+        let mut rates = Vec::new();
+        let mut next = HashMap::new();
+        for (name, data) in self.networks.iter() {
+            let (rx, tx) = (data.total_received(), data.total_transmitted());
+            let (prx, ptx) = self.net_prev.get(name).copied().unwrap_or((rx, tx));
+            let rx_rate = rx.saturating_sub(prx) as f64 / elapsed;
+            let tx_rate = tx.saturating_sub(ptx) as f64 / elapsed;
+            rates.push((name.clone(), rx_rate, tx_rate));
+            next.insert(name.clone(), (rx, tx));
+        }
+        self.net_prev = next;
+        self.net_prev_at = now;
+        rates.sort_by(|a, b| a.0.cmp(&b.0));
+        rates
+    }
+
+    // refresh every collector and redraw the whole dashboard
+    fn draw(&mut self, f: &mut Frame) {
+        self.sys.refresh_all();
+        self.networks.refresh();
+        self.disks.refresh();
+        self.components.refresh();
+
+        let now = Instant::now();
+        let size = f.size();
+
+        // optional panels only take a middle row when at least one is enabled
+        let any_panel = self.show_network || self.show_disks || self.show_temps;
+        let mid = if any_panel { Constraint::Length(8) } else { Constraint::Length(0) };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Length(10),
+                mid,
+                Constraint::Min(5),
+                Constraint::Length(1),
+            ])
+            .split(size);
+
+        // CPU / Memory trend charts sit side by side along the top
+        let top = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[0]);
+
+        // sample CPU and memory before drawing either trend
+        let cpu_usage = self.sys.global_cpu_info().cpu_usage() as f64;
+        self.cpu_hist.push(now, cpu_usage);
+        let mem_percent = if self.sys.total_memory() > 0 {
+            (self.sys.used_memory() as f64 / self.sys.total_memory() as f64) * 100.0
+        } else {
+            0.0
+        };
+        self.mem_hist.push(now, mem_percent);
+
+        self.render_cpu(f, top[0], now);
+        self.render_memory(f, top[1], now);
+
+        if any_panel {
+            self.render_panels(f, chunks[1], now);
+        }
+
+        self.render_processes(f, chunks[2]);
+
+        // transient status line at the very bottom
+        if let Some((msg, _)) = &self.status {
+            let line = Paragraph::new(msg.as_str()).style(Style::default().fg(Color::Yellow));
+            f.render_widget(line, chunks[3]);
+        }
+
+        // kill confirmation popup, drawn on top of everything else
+        if let Mode::ConfirmKill { pid, name, signal } = &self.mode {
+            let area = centered_rect(50, 7, size);
+            let text = format!(
+                "Kill {} (pid {})?\n\nsignal: {}\n[<-/->] signal  [y] confirm  [n] cancel",
+                name,
+                pid,
+                signal.label()
+            );
+            let popup = Paragraph::new(text).block(
+                Block::default()
+                    .title("Confirm kill")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Red)),
+            );
+            f.render_widget(Clear, area);
+            f.render_widget(popup, area);
+        }
+    }
+
+    // CPU half: either the aggregated trend chart or a per-core gauge grid
+    fn render_cpu(&self, f: &mut Frame, area: Rect, now: Instant) {
+        if self.cpu_per_core {
+            render_cpu_cores(f, area, &self.sys);
+        } else {
+            let points = self.cpu_hist.points(now);
+            let chart = history_chart(
+                "CPU",
+                &points,
+                self.cpu_hist.bounds(),
+                self.cpu_hist.window_secs(),
+                self.theme.cpu_color(),
+            );
+            f.render_widget(chart, area);
+        }
+    }
+
+    fn render_memory(&self, f: &mut Frame, area: Rect, now: Instant) {
+        let points = self.mem_hist.points(now);
+        let chart = history_chart(
+            "Memory",
+            &points,
+            self.mem_hist.bounds(),
+            self.mem_hist.window_secs(),
+            self.theme.mem_color(),
+        );
+        f.render_widget(chart, area);
+    }
+
+    // lay out the enabled optional panels side by side and render each
+    fn render_panels(&mut self, f: &mut Frame, area: Rect, now: Instant) {
+        let mut enabled: Vec<Panel> = Vec::new();
+        if self.show_network {
+            enabled.push(Panel::Network);
+        }
+        if self.show_disks {
+            enabled.push(Panel::Disks);
+        }
+        if self.show_temps {
+            enabled.push(Panel::Temps);
+        }
+        if enabled.is_empty() {
+            return;
+        }
+
+        let constraints: Vec<Constraint> = enabled
+            .iter()
+            .map(|_| Constraint::Ratio(1, enabled.len() as u32))
+            .collect();
+        let areas = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints)
+            .split(area);
+
+        // network rates must be computed once per tick (they mutate net_prev)
+        let net = if self.show_network {
+            Some(self.network_rates(now))
+        } else {
+            None
+        };
+
+        for (panel, a) in enabled.iter().zip(areas.iter()) {
+            match panel {
+                Panel::Network => render_network(f, *a, net.as_deref().unwrap_or(&[])),
+                Panel::Disks => render_disks(f, *a, &self.disks),
+                Panel::Temps => render_temps(f, *a, &self.components),
+            }
+        }
+    }
+
+    fn render_processes(&mut self, f: &mut Frame, area: Rect) {
+        // collect one snapshot and sort it in-place by the active key
+        let mut processes: Vec<_> = self.sys.processes().values().collect();
+        sort_processes(&mut processes, self.sort_key, self.sort_desc);
+
+        // an optional cap from config; 0 keeps the full, scrollable list
+        if self.process_count > 0 {
+            processes.truncate(self.process_count);
+        }
+
+        // remember the row order so input handling can map selection -> pid
+        self.visible = processes
+            .iter()
+            .map(|p| (p.pid(), p.name().to_string()))
+            .collect();
+
+        let rows: Vec<Row> = processes
+            .iter()
+            .map(|p| {
+                Row::new(vec![
+                    Cell::from(p.pid().to_string()),
+                    Cell::from(p.name().to_string()),
+                    Cell::from(format!("{:.1}", p.cpu_usage())),
+                    Cell::from((p.memory() / 1024 / 1024).to_string()),
+                ])
+            })
+            .collect();
+
+        let header = Row::new(vec![
+            Cell::from("PID"),
+            Cell::from("NAME"),
+            Cell::from("CPU%"),
+            Cell::from("MEM MB"),
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+        let title = format!(
+            "Processes  [sort: {} {}]",
+            self.sort_key.label(),
+            if self.sort_desc { "v" } else { "^" }
+        );
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(8),
+                Constraint::Min(20),
+                Constraint::Length(8),
+                Constraint::Length(10),
+            ],
+        )
+        .header(header)
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        // table body height minus borders and the header row
+        self.page_len = (area.height as usize).saturating_sub(3).max(1);
+
+        f.render_stateful_widget(table, area, &mut self.table_state);
+    }
+
+    // flash a transient message in the bottom status line
+    fn set_status(&mut self, msg: String, now: Instant) {
+        self.status = Some((msg, now));
+    }
+
+    // drop the status message once it has outlived its time-to-live
+    fn expire_status(&mut self) {
+        if let Some((_, when)) = &self.status {
+            if when.elapsed() >= self.status_ttl {
+                self.status = None;
+            }
+        }
+    }
+}
+
+// the optional panels that share the dashboard's middle row
+enum Panel {
+    Network,
+    Disks,
+    Temps,
+}
+
+// restores the terminal out of raw mode / the alternate screen. its existence
+// is the guarantee: whether `main` returns normally, bails on an error, or the
+// process panics, the `Drop` impl (and the panic hook installed alongside it)
+// runs the same cleanup so the user is never left with a corrupted terminal.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    // enter raw mode + the alternate screen and arm both cleanup paths
+    fn new() -> Result<Self, io::Error> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen)?;
+
+        // chain a hook that restores the terminal before the default one prints
+        // the panic message, otherwise the backtrace lands on a garbled screen
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let _ = restore_terminal();
+            previous(info);
+        }));
+
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        // best-effort: we are tearing down, so there is nothing useful to do
+        // with an error here beyond not masking whatever caused the exit
+        let _ = restore_terminal();
+    }
+}
+
+// leave the alternate screen, drop raw mode and show the cursor again
+fn restore_terminal() -> Result<(), io::Error> {
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen)?;
+    execute!(io::stdout(), crossterm::cursor::Show)?;
+    Ok(())
+}
 
 fn main() -> Result<(), io::Error> {
-    // disable line buffering, auto line buffering and detect keypresses
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    // CLI flags override the config file, which overrides the built-in defaults
+    let config = Config::resolve(Cli::parse(), load_file_config());
 
-    let backend = CrosstermBackend::new(stdout);
+    // installs the panic hook and arms the Drop-based cleanup for every exit path
+    let _guard = TerminalGuard::new()?;
+
+    let backend = CrosstermBackend::new(io::stdout());
     let mut terminal = Terminal::new(backend)?;
 
-    // system obj for metric collection
-    let mut sys = System::new_all();
+    let mut app = App::new(&config);
 
-    let tick_rate = Duration::from_millis(800);
+    let tick_rate = config.tick_rate;
     let mut last_tick = Instant::now();
 
     loop {
-        terminal.draw(|f| {
-            sys.refresh_all();
-
-            let size = f.size();
-
-            let chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .margin(1)
-                .constraints([
-                    Constraint::Length(3),
-                    Constraint::Length(3),
-                    Constraint::Min(5),
-                ])
-                .split(size);
+        terminal.draw(|f| app.draw(f))?;
 
-            // CPU
-            let cpu_usage = sys.global_cpu_info().cpu_usage();
-
-            let cpu_gauge = Gauge::default()
-                .block(Block::default().title("CPU").borders(Borders::ALL))
-                .gauge_style(Style::default().fg(Color::Green))
-                .percent(cpu_usage as u16);
-
-            // Memory
-            let mem_percent = if sys.total_memory() > 0 {
-                ((sys.used_memory() as f64 / sys.total_memory() as f64) * 100.0) as u16
-            } else {
-                0
-            };
-
-            let mem_gauge = Gauge::default()
-                .block(Block::default().title("Memory").borders(Borders::ALL))
-                .gauge_style(Style::default().fg(Color::Cyan))
-                .percent(mem_percent);
-
-            f.render_widget(cpu_gauge, chunks[0]);
-            f.render_widget(mem_gauge, chunks[1]);
-
-            // Processes
-            let mut processes: Vec<_> = sys.processes().values().collect();
-
-            processes.sort_by(|a, b| {
-                b.cpu_usage()
-                    .partial_cmp(&a.cpu_usage())
-                    .unwrap()
-            });
-
-            let items: Vec<ListItem> = processes
-                .iter()
-                .take(10)
-                .map(|p| {
-                    ListItem::new(format!(
-                        "PID: {:<6} | {:<20} | CPU: {:>5.1}% | MEM: {:>5} MB",
-                        p.pid(),
-                        p.name(),
-                        p.cpu_usage(),
-                        p.memory() / 1024
-                    ))
-                })
-                .collect();
-
-            let process_list = List::new(items)
-                .block(Block::default().title("Top Processes").borders(Borders::ALL));
-
-            f.render_widget(process_list, chunks[2]);
-        })?;
+        app.expire_status();
 
         let timeout = tick_rate
             .checked_sub(last_tick.elapsed())
@@ -115,8 +617,69 @@ fn main() -> Result<(), io::Error> {
 
         if event::poll(timeout)? {
             if let Event::Key(key) = event::read()? {
-                if key.code == KeyCode::Char('q') {
-                    break;
+                match &mut app.mode {
+                    // popup is open: pick a signal and confirm / cancel
+                    Mode::ConfirmKill { pid, signal, .. } => match key.code {
+                        KeyCode::Left | KeyCode::Right => toggle_signal(signal),
+                        KeyCode::Char('y') => {
+                            let pid = *pid;
+                            let signal = *signal;
+                            let msg = kill_process(&app.sys, pid, signal);
+                            app.set_status(msg, Instant::now());
+                            app.mode = Mode::Normal;
+                        }
+                        KeyCode::Char('n') | KeyCode::Esc => app.mode = Mode::Normal,
+                        _ => {}
+                    },
+                    // normal table navigation
+                    Mode::Normal => match key.code {
+                        KeyCode::Char('q') => break,
+                        KeyCode::Down => move_selection(&mut app.table_state, app.visible.len(), 1),
+                        KeyCode::Up => move_selection(&mut app.table_state, app.visible.len(), -1),
+                        KeyCode::PageDown => {
+                            let d = app.page_len as isize;
+                            move_selection(&mut app.table_state, app.visible.len(), d)
+                        }
+                        KeyCode::PageUp => {
+                            let d = app.page_len as isize;
+                            move_selection(&mut app.table_state, app.visible.len(), -d)
+                        }
+                        // toggle sort columns; pressing the active column flips direction
+                        KeyCode::Char('c') => {
+                            set_sort(&mut app.sort_key, &mut app.sort_desc, SortKey::Cpu)
+                        }
+                        KeyCode::Char('m') => {
+                            set_sort(&mut app.sort_key, &mut app.sort_desc, SortKey::Memory)
+                        }
+                        KeyCode::Char('p') => {
+                            set_sort(&mut app.sort_key, &mut app.sort_desc, SortKey::Pid)
+                        }
+                        KeyCode::Char('n') => {
+                            set_sort(&mut app.sort_key, &mut app.sort_desc, SortKey::Name)
+                        }
+                        // toggle between aggregated CPU and the per-core grid
+                        KeyCode::Char('g') => app.cpu_per_core = !app.cpu_per_core,
+                        // enable/disable the optional dashboard panels
+                        KeyCode::Char('N') => app.show_network = !app.show_network,
+                        KeyCode::Char('D') => app.show_disks = !app.show_disks,
+                        KeyCode::Char('T') => app.show_temps = !app.show_temps,
+                        // open the kill confirmation for the highlighted process
+                        KeyCode::Char('k') => {
+                            if let Some((pid, name)) = app
+                                .table_state
+                                .selected()
+                                .and_then(|i| app.visible.get(i))
+                                .cloned()
+                            {
+                                app.mode = Mode::ConfirmKill {
+                                    pid,
+                                    name,
+                                    signal: KillSignal::Term,
+                                };
+                            }
+                        }
+                        _ => {}
+                    },
                 }
             }
         }
@@ -126,10 +689,261 @@ fn main() -> Result<(), io::Error> {
         }
     }
 
-    // cleanup and back to terminal
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()?;
-
+    // the terminal is restored by `_guard` when it drops at the end of scope
     Ok(())
-}
\ No newline at end of file
+}
+
+// build a right-to-left line chart for a metric's windowed history. the Y axis
+// is padded slightly around the observed min/max so a flat line stays visible.
+fn history_chart<'a>(
+    title: &'a str,
+    points: &'a [(f64, f64)],
+    bounds: (f64, f64),
+    window_secs: f64,
+    color: Color,
+) -> Chart<'a> {
+    let (min, max) = bounds;
+    let pad = ((max - min) * 0.1).max(1.0);
+    let y_min = (min - pad).max(0.0);
+    let y_max = max + pad;
+
+    let dataset = Dataset::default()
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(color))
+        .data(points);
+
+    Chart::new(vec![dataset])
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .x_axis(Axis::default().bounds([-window_secs, 0.0]))
+        .y_axis(
+            Axis::default()
+                .bounds([y_min, y_max])
+                .labels(vec![
+                    Span::raw(format!("{:.0}", y_min)),
+                    Span::raw(format!("{:.0}", y_max)),
+                ]),
+        )
+}
+
+// render one gauge per logical core in a roughly square grid built from nested
+// Layout splits, colouring each by its load so hot cores stand out
+fn render_cpu_cores(f: &mut Frame, area: Rect, sys: &System) {
+    let cpus = sys.cpus();
+    let n = cpus.len();
+    if n == 0 {
+        return;
+    }
+
+    let cols = (n as f64).sqrt().ceil() as usize;
+    let rows = n.div_ceil(cols);
+
+    let row_constraints: Vec<Constraint> =
+        (0..rows).map(|_| Constraint::Ratio(1, rows as u32)).collect();
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(row_constraints)
+        .split(area);
+
+    for (r, row_area) in row_areas.iter().enumerate() {
+        let col_constraints: Vec<Constraint> =
+            (0..cols).map(|_| Constraint::Ratio(1, cols as u32)).collect();
+        let col_areas = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(col_constraints)
+            .split(*row_area);
+
+        for (c, cell) in col_areas.iter().enumerate() {
+            let idx = r * cols + c;
+            if idx >= n {
+                break;
+            }
+            let usage = cpus[idx].cpu_usage();
+            let gauge = Gauge::default()
+                .block(Block::default().title(format!("{}", idx)).borders(Borders::ALL))
+                .gauge_style(Style::default().fg(load_color(usage)))
+                .percent(usage as u16);
+            f.render_widget(gauge, *cell);
+        }
+    }
+}
+
+// green / yellow / red by load so a glance shows which cores are saturated
+fn load_color(usage: f32) -> Color {
+    if usage >= 80.0 {
+        Color::Red
+    } else if usage >= 50.0 {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
+
+// per-interface throughput as a list of rx/tx rates
+fn render_network(f: &mut Frame, area: Rect, rates: &[(String, f64, f64)]) {
+    let lines: Vec<Row> = rates
+        .iter()
+        .map(|(name, rx, tx)| {
+            Row::new(vec![
+                Cell::from(name.clone()),
+                Cell::from(format!("rx {}/s", human_bytes(*rx))),
+                Cell::from(format!("tx {}/s", human_bytes(*tx))),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        lines,
+        [Constraint::Min(8), Constraint::Length(14), Constraint::Length(14)],
+    )
+    .block(Block::default().title("Network").borders(Borders::ALL));
+
+    f.render_widget(table, area);
+}
+
+// mounted filesystems with used/total space
+fn render_disks(f: &mut Frame, area: Rect, disks: &Disks) {
+    let rows: Vec<Row> = disks
+        .iter()
+        .map(|d| {
+            let total = d.total_space();
+            let used = total.saturating_sub(d.available_space());
+            Row::new(vec![
+                Cell::from(d.mount_point().to_string_lossy().into_owned()),
+                Cell::from(format!("{} / {}", human_bytes(used as f64), human_bytes(total as f64))),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(rows, [Constraint::Min(10), Constraint::Length(20)])
+        .block(Block::default().title("Disks").borders(Borders::ALL));
+
+    f.render_widget(table, area);
+}
+
+// thermal sensors, coloured by temperature so a hot component stands out
+fn render_temps(f: &mut Frame, area: Rect, components: &Components) {
+    let rows: Vec<Row> = components
+        .iter()
+        .map(|c| {
+            let temp = c.temperature();
+            Row::new(vec![
+                Cell::from(c.label().to_string()),
+                Cell::from(format!("{:.1} C", temp)),
+            ])
+            .style(Style::default().fg(temp_color(temp)))
+        })
+        .collect();
+
+    let table = Table::new(rows, [Constraint::Min(10), Constraint::Length(10)])
+        .block(Block::default().title("Temps").borders(Borders::ALL));
+
+    f.render_widget(table, area);
+}
+
+// green / yellow / red thresholds for sensor temperatures in Celsius
+fn temp_color(temp: f32) -> Color {
+    if temp >= 80.0 {
+        Color::Red
+    } else if temp >= 60.0 {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
+
+// compact human-readable byte count (B / KB / MB / GB)
+fn human_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+// sort a collected snapshot by the active key without re-querying sysinfo
+fn sort_processes(processes: &mut [&sysinfo::Process], key: SortKey, desc: bool) {
+    processes.sort_by(|a, b| {
+        let ord = match key {
+            SortKey::Cpu => a
+                .cpu_usage()
+                .partial_cmp(&b.cpu_usage())
+                .unwrap_or(std::cmp::Ordering::Equal),
+            SortKey::Memory => a.memory().cmp(&b.memory()),
+            SortKey::Pid => a.pid().cmp(&b.pid()),
+            SortKey::Name => a.name().cmp(b.name()),
+        };
+        if desc {
+            ord.reverse()
+        } else {
+            ord
+        }
+    });
+}
+
+// switch the active sort column, or flip direction when it is already active
+fn set_sort(current: &mut SortKey, desc: &mut bool, key: SortKey) {
+    if *current == key {
+        *desc = !*desc;
+    } else {
+        *current = key;
+        *desc = true;
+    }
+}
+
+// move the highlight by `delta` rows, clamped to the available range
+fn move_selection(state: &mut TableState, len: usize, delta: isize) {
+    if len == 0 {
+        state.select(None);
+        return;
+    }
+    let current = state.selected().unwrap_or(0) as isize;
+    let next = (current + delta).clamp(0, len as isize - 1);
+    state.select(Some(next as usize));
+}
+
+// flip between the two offered signals in the popup
+fn toggle_signal(signal: &mut KillSignal) {
+    *signal = match *signal {
+        KillSignal::Term => KillSignal::Kill,
+        KillSignal::Kill => KillSignal::Term,
+    };
+}
+
+// send the chosen signal to the process and report a human-readable outcome
+fn kill_process(sys: &System, pid: Pid, signal: KillSignal) -> String {
+    let Some(process) = sys.process(pid) else {
+        return format!("process {} is gone", pid);
+    };
+    let sig = match signal {
+        KillSignal::Term => sysinfo::Signal::Term,
+        KillSignal::Kill => sysinfo::Signal::Kill,
+    };
+    match process.kill_with(sig) {
+        // the signal is unsupported on this platform -- fall back to the default kill
+        None => {
+            if process.kill() {
+                format!("sent default kill to pid {}", pid)
+            } else {
+                format!("failed to kill pid {}", pid)
+            }
+        }
+        Some(true) => format!("sent {} to pid {}", signal.label(), pid),
+        Some(false) => format!("failed to send {} to pid {}", signal.label(), pid),
+    }
+}
+
+// centre a `width` x `height` rectangle inside `area` for popups
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let x = area.x + area.width.saturating_sub(width) / 2;
+    let y = area.y + area.height.saturating_sub(height) / 2;
+    Rect {
+        x,
+        y,
+        width: width.min(area.width),
+        height: height.min(area.height),
+    }
+}